@@ -3,13 +3,173 @@
 //! [dependencies]
 //! serde_json = "1.0"
 //! chrono = "0.4"
+//! serde = { version = "1.0", features = ["derive"] }
+//! ron = "0.8"
+//! ignore = "0.4"
+//! radix_trie = "0.2"
 //! ```
 
-use serde_json::Value;
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use radix_trie::{Trie, TrieCommon};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::collections::{hash_map::DefaultHasher, BTreeMap, HashMap, HashSet};
 use std::env;
-use std::io::{self, Read, Write};
-use std::fs::File;
-use chrono::Local;
+use std::io::{self, BufRead, Read, Write};
+use std::fs::{File, OpenOptions};
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use chrono::{Local, Utc};
+
+/// Per-tool logging knobs, loaded once at startup from `$ALPINE_HOOK_CONFIG`
+/// or `~/.config/alpine/hook.ron`. Missing or unparsable config falls back
+/// to "log everything, at summary verbosity, untruncated".
+#[derive(Debug, Deserialize, Default)]
+#[serde(default)]
+struct Config {
+    enabled_tools: HashMap<String, bool>,
+    verbosity: Verbosity,
+    truncate_len: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, Default, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum Verbosity {
+    #[default]
+    Summary,
+    Full,
+}
+
+fn config_path() -> Option<PathBuf> {
+    if let Ok(path) = env::var("ALPINE_HOOK_CONFIG") {
+        return Some(PathBuf::from(path));
+    }
+    env::var("HOME").ok().map(|home| Path::new(&home).join(".config/alpine/hook.ron"))
+}
+
+fn load_config() -> Config {
+    let Some(path) = config_path() else {
+        return Config::default();
+    };
+    let Ok(contents) = std::fs::read_to_string(&path) else {
+        return Config::default();
+    };
+
+    if path.extension().and_then(|e| e.to_str()) == Some("json") {
+        serde_json::from_str(&contents).unwrap_or_default()
+    } else {
+        ron::from_str(&contents).unwrap_or_default()
+    }
+}
+
+fn is_tool_enabled(config: &Config, tool: &str) -> bool {
+    *config.enabled_tools.get(tool).unwrap_or(&true)
+}
+
+/// Build the ignore matcher for a single event from the nearest `.gitignore`
+/// walking up from the reported `file_path` (not the process's cwd, so a
+/// `.gitignore` in a subdirectory below cwd is still honored), plus any
+/// extra globs in `$ALPINE_HOOK_IGNORE` (comma-separated, e.g.
+/// `"target/**,*.lock"`).
+fn build_ignore_matcher(file_path: &str) -> Option<Gitignore> {
+    let start_dir = Path::new(file_path).parent().unwrap_or_else(|| Path::new("."));
+
+    let mut dir = Some(start_dir);
+    let mut gitignore_dir = None;
+    let mut gitignore_file = None;
+    while let Some(d) = dir {
+        let candidate = d.join(".gitignore");
+        if candidate.exists() {
+            gitignore_dir = Some(d);
+            gitignore_file = Some(candidate);
+            break;
+        }
+        dir = d.parent();
+    }
+
+    let root = gitignore_dir.unwrap_or(start_dir);
+    let mut builder = GitignoreBuilder::new(root);
+    if let Some(candidate) = gitignore_file {
+        let _ = builder.add(candidate);
+    }
+
+    if let Ok(patterns) = env::var("ALPINE_HOOK_IGNORE") {
+        for pattern in patterns.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let _ = builder.add_line(None, pattern);
+        }
+    }
+
+    builder.build().ok()
+}
+
+fn is_ignored(path: &str) -> bool {
+    build_ignore_matcher(path)
+        .map(|m| m.matched_path_or_any_parents(path, false).is_ignore())
+        .unwrap_or(false)
+}
+
+/// Known-dangerous command prefixes, keyed for longest-prefix lookup.
+/// Multi-token rules (`git push --force`) are stored as the full
+/// normalized prefix string.
+fn build_danger_trie() -> Trie<String, &'static str> {
+    let mut trie = Trie::new();
+    trie.insert("rm -rf".to_string(), "recursively deletes files without confirmation");
+    trie.insert("rm -fr".to_string(), "recursively deletes files without confirmation");
+    trie.insert("git push --force".to_string(), "force-push can overwrite remote history");
+    trie.insert("git push -f".to_string(), "force-push can overwrite remote history");
+    trie.insert("dd".to_string(), "can overwrite raw disks/devices");
+    trie.insert("chmod -R 777".to_string(), "grants world-writable permissions recursively");
+    trie.insert("mkfs".to_string(), "reformats a filesystem, destroying its contents");
+    trie
+}
+
+/// Strip the leading token's path component (so `/usr/bin/rm -rf` matches
+/// the same rule as `rm -rf`) and leave the rest of the command as-is.
+fn normalize_bash_command(command: &str) -> String {
+    let mut tokens = command.split_whitespace();
+    let Some(first) = tokens.next() else {
+        return String::new();
+    };
+    let first = Path::new(first).file_name().and_then(|s| s.to_str()).unwrap_or(first);
+    std::iter::once(first).chain(tokens).collect::<Vec<_>>().join(" ")
+}
+
+fn is_pipe_to_shell(command: &str) -> bool {
+    let lower = command.to_lowercase();
+    (lower.contains("http://") || lower.contains("https://"))
+        && (lower.contains("| sh") || lower.contains("|sh") || lower.contains("| bash") || lower.contains("|bash"))
+}
+
+/// Longest-prefix match against `trie`, falling back to a separate
+/// piped-download-to-shell heuristic. Returns the reason the command is risky.
+fn classify_bash_risk<'a>(command: &str, trie: &'a Trie<String, &'static str>) -> Option<&'a str> {
+    let normalized = normalize_bash_command(command);
+    if let Some(sub_trie) = trie.get_ancestor(&normalized) {
+        if let (Some(key), Some(reason)) = (sub_trie.key(), sub_trie.value()) {
+            let rest = &normalized[key.len()..];
+            if rest.is_empty() || rest.starts_with(' ') {
+                return Some(reason);
+            }
+        }
+    }
+
+    if is_pipe_to_shell(command) {
+        return Some("downloads remote content and pipes it directly into a shell");
+    }
+
+    None
+}
+
+/// Shorten `s` to `config.truncate_len` characters, if set, for noisy
+/// fields like Bash commands and file paths.
+fn truncate(s: &str, config: &Config) -> String {
+    match config.truncate_len {
+        Some(max) if s.chars().count() > max => {
+            format!("{}...", s.chars().take(max).collect::<String>())
+        }
+        _ => s.to_string(),
+    }
+}
 
 fn main() -> io::Result<()> {
     // Read JSON input from Claude Code
@@ -23,111 +183,288 @@ fn main() -> io::Result<()> {
     
     // Get timestamp
     let timestamp = Local::now().format("%H:%M:%S").to_string();
-    
+
+    let config = load_config();
+    let danger_trie = build_danger_trie();
+
+    let hook_event_name = data["hook_event_name"].as_str().unwrap_or("");
+    let session_id = data["session_id"].as_str().unwrap_or("unknown");
+
     // Check if this is a subagent:stop event
-    if let Some(hook_event) = data["hook_event_name"].as_str() {
-        if hook_event == "SubagentStop" {
-            handle_subagent_stop(&data, &timestamp);
-            return Ok(());
-        }
+    if hook_event_name == "SubagentStop" {
+        handle_subagent_stop(&data, &timestamp, &config);
+        print_tool_summary(session_id, &timestamp);
+        return Ok(());
     }
-    
+
+    // A top-level Stop event closes out the session; report the same rollup.
+    if hook_event_name == "Stop" {
+        print_tool_summary(session_id, &timestamp);
+        return Ok(());
+    }
+
     // Check both possible field names for tool name (for compatibility)
     let tool_name = data["tool_name"].as_str()
         .or_else(|| data["tool"].as_str())
         .unwrap_or("");
-    
+
     // Get tool input - check both possible field names
     let tool_input = data["tool_input"].as_object()
         .or_else(|| data["args"].as_object());
-    
-    // Process and display all tool calls
-    match tool_name {
-        "TodoWrite" => {
-            handle_todo_write(&data, &timestamp, tool_input);
-        }
-        "Read" => {
-            if let Some(input) = tool_input {
-                if let Some(file_path) = input["file_path"].as_str() {
-                    eprintln!("[{}] [READ] Reading file: {}", timestamp, file_path);
+
+    // Pre/Post pairs bracket a single tool invocation; stash the start time
+    // on Pre and turn it into a duration sample on Post.
+    match hook_event_name {
+        "PreToolUse" => record_tool_start(session_id, tool_name, tool_input),
+        "PostToolUse" => record_tool_completion(session_id, tool_input),
+        _ => {}
+    }
+
+    // Process and display all tool calls, unless the config silences this tool.
+    // PostToolUse only drives timing (above) — without this guard, a
+    // Pre/Post pair would print and record every tool call twice.
+    if hook_event_name != "PostToolUse" && is_tool_enabled(&config, tool_name) {
+        match tool_name {
+            "TodoWrite" => {
+                handle_todo_write(&data, &timestamp, tool_input);
+            }
+            "Read" => {
+                if let Some(input) = tool_input {
+                    if let Some(file_path) = input["file_path"].as_str() {
+                        if !is_ignored(file_path) {
+                            eprintln!("[{}] [READ] Reading file: {}", timestamp, truncate(file_path, &config));
+                        }
+                    }
                 }
             }
-        }
-        "Write" => {
-            if let Some(input) = tool_input {
-                if let Some(file_path) = input["file_path"].as_str() {
-                    eprintln!("[{}] [WRITE] Writing file: {}", timestamp, file_path);
+            "Write" => {
+                if let Some(input) = tool_input {
+                    if let Some(file_path) = input["file_path"].as_str() {
+                        if !is_ignored(file_path) {
+                            eprintln!("[{}] [WRITE] Writing file: {}", timestamp, truncate(file_path, &config));
+                        }
+                    }
                 }
             }
-        }
-        "Edit" | "MultiEdit" => {
-            if let Some(input) = tool_input {
-                if let Some(file_path) = input["file_path"].as_str() {
-                    eprintln!("[{}] [EDIT] Editing file: {}", timestamp, file_path);
+            "Edit" | "MultiEdit" => {
+                if let Some(input) = tool_input {
+                    if let Some(file_path) = input["file_path"].as_str() {
+                        if !is_ignored(file_path) {
+                            eprintln!("[{}] [EDIT] Editing file: {}", timestamp, truncate(file_path, &config));
+                        }
+                    }
                 }
             }
-        }
-        "Bash" => {
-            if let Some(input) = tool_input {
-                if let Some(command) = input["command"].as_str() {
-                    eprintln!("[{}] [BASH] Executing: {}", timestamp, command);
+            "Bash" => {
+                if let Some(input) = tool_input {
+                    if let Some(command) = input["command"].as_str() {
+                        eprintln!("[{}] [BASH] Executing: {}", timestamp, truncate(command, &config));
+                        if let Some(reason) = classify_bash_risk(command, &danger_trie) {
+                            eprintln!("[{}] [BASH][RISK] {} — {}", timestamp, truncate(command, &config), reason);
+                        }
+                    }
                 }
             }
-        }
-        "Grep" => {
-            if let Some(input) = tool_input {
-                if let Some(pattern) = input["pattern"].as_str() {
-                    let path = input["path"].as_str().unwrap_or(".");
-                    eprintln!("[{}] [GREP] Searching for '{}' in {}", timestamp, pattern, path);
+            "Grep" => {
+                if let Some(input) = tool_input {
+                    if let Some(pattern) = input["pattern"].as_str() {
+                        let path = input["path"].as_str().unwrap_or(".");
+                        eprintln!("[{}] [GREP] Searching for '{}' in {}", timestamp, pattern, path);
+                    }
                 }
             }
-        }
-        "Glob" => {
-            if let Some(input) = tool_input {
-                if let Some(pattern) = input["pattern"].as_str() {
-                    let path = input["path"].as_str().unwrap_or(".");
-                    eprintln!("[{}] [GLOB] Finding files matching '{}' in {}", timestamp, pattern, path);
+            "Glob" => {
+                if let Some(input) = tool_input {
+                    if let Some(pattern) = input["pattern"].as_str() {
+                        let path = input["path"].as_str().unwrap_or(".");
+                        if !is_ignored(path) {
+                            eprintln!("[{}] [GLOB] Finding files matching '{}' in {}", timestamp, pattern, path);
+                        }
+                    }
                 }
             }
-        }
-        "LS" => {
-            if let Some(input) = tool_input {
-                if let Some(path) = input["path"].as_str() {
-                    eprintln!("[{}] [LS] Listing directory: {}", timestamp, path);
+            "LS" => {
+                if let Some(input) = tool_input {
+                    if let Some(path) = input["path"].as_str() {
+                        if !is_ignored(path) {
+                            eprintln!("[{}] [LS] Listing directory: {}", timestamp, truncate(path, &config));
+                        }
+                    }
                 }
             }
-        }
-        "WebFetch" => {
-            if let Some(input) = tool_input {
-                if let Some(url) = input["url"].as_str() {
-                    eprintln!("[{}] [WEB] Fetching: {}", timestamp, url);
+            "WebFetch" => {
+                if let Some(input) = tool_input {
+                    if let Some(url) = input["url"].as_str() {
+                        eprintln!("[{}] [WEB] Fetching: {}", timestamp, url);
+                    }
                 }
             }
-        }
-        "WebSearch" => {
-            if let Some(input) = tool_input {
-                if let Some(query) = input["query"].as_str() {
-                    eprintln!("[{}] [SEARCH] Searching web for: {}", timestamp, query);
+            "WebSearch" => {
+                if let Some(input) = tool_input {
+                    if let Some(query) = input["query"].as_str() {
+                        eprintln!("[{}] [SEARCH] Searching web for: {}", timestamp, query);
+                    }
                 }
             }
+            "Task" => {
+                if let Some(input) = tool_input {
+                    if let Some(description) = input["description"].as_str() {
+                        eprintln!("[{}] [TASK] Launching agent: {}", timestamp, description);
+                    }
+                }
+            }
+            "" => {
+                // No tool name, ignore
+            }
+            _ => {
+                // Other tools - show generic message
+                eprintln!("[{}] [TOOL] Using: {}", timestamp, tool_name);
+            }
         }
-        "Task" => {
+
+        if config.verbosity == Verbosity::Full {
             if let Some(input) = tool_input {
-                if let Some(description) = input["description"].as_str() {
-                    eprintln!("[{}] [TASK] Launching agent: {}", timestamp, description);
-                }
+                eprintln!("[{}] [TOOL:FULL] {}", timestamp, Value::Object(input.clone()));
             }
         }
-        "" => {
-            // No tool name, ignore
+    }
+
+    if hook_event_name != "PostToolUse" && !tool_name.is_empty() {
+        record_event("tool", tool_name, tool_input);
+    }
+
+    Ok(())
+}
+
+/// Append a single JSON line to `$ALPINE_EVENT_LOG`, if set. Best-effort: a
+/// missing or unwritable sink should never affect the hook's exit status.
+fn record_event(event: &str, tool: &str, tool_input: Option<&serde_json::Map<String, Value>>) {
+    let Ok(log_path) = env::var("ALPINE_EVENT_LOG") else {
+        return;
+    };
+
+    let record = json!({
+        "ts": Utc::now().timestamp_millis(),
+        "event": event,
+        "tool": tool,
+        "fields": tool_input.map(|m| Value::Object(m.clone())).unwrap_or(Value::Null),
+    });
+
+    if let Ok(mut file) = OpenOptions::new().create(true).append(true).open(&log_path) {
+        if let Ok(line) = serde_json::to_string(&record) {
+            let _ = writeln!(file, "{}", line);
         }
-        _ => {
-            // Other tools - show generic message
-            eprintln!("[{}] [TOOL] Using: {}", timestamp, tool_name);
+    }
+}
+
+/// Key a tool invocation by session plus a hash of its input, so the matching
+/// Pre/Post events can be paired up without relying on process-local state.
+fn tool_invocation_key(session_id: &str, tool_input: Option<&serde_json::Map<String, Value>>) -> String {
+    let mut hasher = DefaultHasher::new();
+    tool_input.map(|m| Value::Object(m.clone())).unwrap_or(Value::Null).to_string().hash(&mut hasher);
+    format!("{}:{:x}", session_id, hasher.finish())
+}
+
+fn tool_starts_path(session_id: &str) -> PathBuf {
+    env::temp_dir().join(format!("alpine_tool_starts_{}.json", session_id))
+}
+
+fn tool_metrics_path(session_id: &str) -> PathBuf {
+    env::temp_dir().join(format!("alpine_tool_metrics_{}.json", session_id))
+}
+
+fn load_json_array(path: &Path) -> Vec<Value> {
+    std::fs::read_to_string(path).ok()
+        .and_then(|s| serde_json::from_str::<Value>(&s).ok())
+        .and_then(|v| v.as_array().cloned())
+        .unwrap_or_default()
+}
+
+fn save_json(path: &Path, value: &Value) {
+    if let Ok(mut file) = File::create(path) {
+        if let Ok(text) = serde_json::to_string(value) {
+            let _ = file.write_all(text.as_bytes());
         }
     }
-    
-    Ok(())
+}
+
+/// Record the start of a PreToolUse/PostToolUse pair to a small on-disk
+/// state file in the temp dir. Starts are a FIFO queue keyed by
+/// `tool_invocation_key` rather than a map: two calls with identical input
+/// (e.g. reading the same file twice) would otherwise collide on one key,
+/// so each Pre queues its own entry and each Post claims the oldest
+/// matching one, keeping repeated identical calls independently timed.
+fn record_tool_start(session_id: &str, tool_name: &str, tool_input: Option<&serde_json::Map<String, Value>>) {
+    let key = tool_invocation_key(session_id, tool_input);
+    let path = tool_starts_path(session_id);
+    let mut starts = load_json_array(&path);
+    starts.push(json!({ "key": key, "tool_name": tool_name, "start_ms": Utc::now().timestamp_millis() }));
+    save_json(&path, &Value::Array(starts));
+}
+
+/// Match a PostToolUse event back to its oldest queued start time, compute
+/// the elapsed duration, and append it to the session's metrics file.
+fn record_tool_completion(session_id: &str, tool_input: Option<&serde_json::Map<String, Value>>) {
+    let key = tool_invocation_key(session_id, tool_input);
+    let starts_path = tool_starts_path(session_id);
+    let mut starts = load_json_array(&starts_path);
+
+    let Some(index) = starts.iter().position(|entry| entry["key"].as_str() == Some(key.as_str())) else {
+        return;
+    };
+    let start_entry = starts.remove(index);
+    save_json(&starts_path, &Value::Array(starts));
+
+    let Some(start_ms) = start_entry["start_ms"].as_i64() else {
+        return;
+    };
+    let tool_name = start_entry["tool_name"].as_str().unwrap_or("unknown");
+    let duration_ms = (Utc::now().timestamp_millis() - start_ms).max(0);
+    let file_path = tool_input
+        .and_then(|m| m.get("file_path"))
+        .and_then(|v| v.as_str());
+
+    let metrics_path = tool_metrics_path(session_id);
+    let mut metrics = load_json_array(&metrics_path);
+    metrics.push(json!({ "tool_name": tool_name, "duration_ms": duration_ms, "file_path": file_path }));
+    save_json(&metrics_path, &Value::Array(metrics));
+}
+
+/// Print a per-tool count/total/mean duration table plus total files
+/// touched, then clear the session's accumulated state.
+fn print_tool_summary(session_id: &str, timestamp: &str) {
+    let metrics_path = tool_metrics_path(session_id);
+    let metrics = load_json_array(&metrics_path);
+    if metrics.is_empty() {
+        return;
+    }
+
+    let mut by_tool: BTreeMap<String, (u64, i64)> = BTreeMap::new();
+    let mut files_touched: HashSet<String> = HashSet::new();
+
+    for m in &metrics {
+        let tool_name = m["tool_name"].as_str().unwrap_or("unknown").to_string();
+        let duration_ms = m["duration_ms"].as_i64().unwrap_or(0);
+        let entry = by_tool.entry(tool_name).or_insert((0, 0));
+        entry.0 += 1;
+        entry.1 += duration_ms;
+        if let Some(file_path) = m["file_path"].as_str() {
+            files_touched.insert(file_path.to_string());
+        }
+    }
+
+    eprintln!("[{}] [METRICS] Tool timing summary for session {}:", timestamp, session_id);
+    for (tool_name, (count, total_ms)) in &by_tool {
+        let mean_ms = *total_ms as f64 / *count as f64;
+        eprintln!(
+            "[{}] [METRICS]   {:<10} count={:<4} total={}ms mean={:.1}ms",
+            timestamp, tool_name, count, total_ms, mean_ms
+        );
+    }
+    eprintln!("[{}] [METRICS] Files touched: {}", timestamp, files_touched.len());
+
+    let _ = std::fs::remove_file(&metrics_path);
+    let _ = std::fs::remove_file(tool_starts_path(session_id));
 }
 
 fn handle_todo_write(data: &Value, timestamp: &str, tool_input: Option<&serde_json::Map<String, Value>>) {
@@ -175,17 +512,101 @@ fn handle_todo_write(data: &Value, timestamp: &str, tool_input: Option<&serde_js
     }
 }
 
-fn handle_subagent_stop(data: &Value, timestamp: &str) {
+fn handle_subagent_stop(data: &Value, timestamp: &str, config: &Config) {
     // Extract subagent stop information
     let session_id = data["session_id"].as_str().unwrap_or("unknown");
     let transcript_path = data["transcript_path"].as_str().unwrap_or("unknown");
     let stop_hook_active = data["stop_hook_active"].as_bool().unwrap_or(false);
     
     eprintln!("[{}] [AGENT] Subagent completed - Session: {}", timestamp, session_id);
-    
+
     // Only process transcript if stop_hook_active is false to prevent loops
     if !stop_hook_active && transcript_path != "unknown" {
-        // Could process the transcript file here if needed
         eprintln!("[{}] [AGENT] Transcript saved to: {}", timestamp, transcript_path);
+
+        if let Some(summary) = summarize_transcript(transcript_path) {
+            print_transcript_summary(timestamp, &summary, config);
+            record_event("agent_summary", "", summary.as_object());
+        }
+    }
+
+    record_event("subagent_stop", "", data.as_object());
+}
+
+/// Stream a subagent's JSONL transcript (one message per line) and tally
+/// message counts, tool usage, and the final assistant message. Malformed
+/// lines are skipped rather than aborting the whole summary.
+fn summarize_transcript(transcript_path: &str) -> Option<Value> {
+    let file = File::open(transcript_path).ok()?;
+    let reader = io::BufReader::new(file);
+
+    let mut user_messages: u64 = 0;
+    let mut assistant_messages: u64 = 0;
+    let mut tool_calls: u64 = 0;
+    let mut tool_histogram: BTreeMap<String, u64> = BTreeMap::new();
+    let mut final_text: Option<String> = None;
+
+    for line in reader.lines() {
+        let Ok(line) = line else { continue };
+        if line.trim().is_empty() {
+            continue;
+        }
+        let Ok(entry) = serde_json::from_str::<Value>(&line) else {
+            continue;
+        };
+
+        let entry_type = entry["type"].as_str().unwrap_or("");
+        match entry_type {
+            "user" => user_messages += 1,
+            "assistant" => assistant_messages += 1,
+            _ => {}
+        }
+
+        let Some(blocks) = entry["message"]["content"].as_array() else {
+            continue;
+        };
+        for block in blocks {
+            match block["type"].as_str() {
+                Some("tool_use") => {
+                    tool_calls += 1;
+                    let name = block["name"].as_str().unwrap_or("unknown").to_string();
+                    *tool_histogram.entry(name).or_insert(0) += 1;
+                }
+                Some("text") if entry_type == "assistant" => {
+                    if let Some(text) = block["text"].as_str() {
+                        final_text = Some(text.to_string());
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    Some(json!({
+        "user_messages": user_messages,
+        "assistant_messages": assistant_messages,
+        "tool_calls": tool_calls,
+        "tool_histogram": tool_histogram,
+        "final_text": final_text,
+    }))
+}
+
+fn print_transcript_summary(timestamp: &str, summary: &Value, config: &Config) {
+    eprintln!(
+        "[{}] [AGENT][SUMMARY] Messages - user: {}, assistant: {}, tool calls: {}",
+        timestamp,
+        summary["user_messages"].as_u64().unwrap_or(0),
+        summary["assistant_messages"].as_u64().unwrap_or(0),
+        summary["tool_calls"].as_u64().unwrap_or(0),
+    );
+
+    if let Some(histogram) = summary["tool_histogram"].as_object() {
+        for (tool, count) in histogram {
+            eprintln!("[{}] [AGENT][SUMMARY]   {} x{}", timestamp, tool, count.as_u64().unwrap_or(0));
+        }
+    }
+
+    if let Some(text) = summary["final_text"].as_str() {
+        eprintln!("[{}] [AGENT][SUMMARY] Final result: {}", timestamp, truncate(text, config));
     }
 }
\ No newline at end of file